@@ -0,0 +1,42 @@
+//! Helpers for removing escape sequences from a [`str`].
+
+use std::borrow::Cow;
+
+/// Removes escapes from `input`, an escape is dropped and the following char is taken literally,
+/// a trailing escape is kept verbatim.
+///
+/// If `input` contains no escape, it is returned borrowed, otherwise the unescaped contents are
+/// collected into an owned [`String`].
+///
+/// # Examples
+/// ```
+/// use strtools::escape::unescape;
+///
+/// assert_eq!(unescape(r"a\:b", '\\'), "a:b");
+/// assert_eq!(unescape("abc", '\\'), "abc");
+/// ```
+pub fn unescape(input: &str, esc: char) -> Cow<'_, str> {
+    if !input.contains(esc) {
+        return Cow::Borrowed(input);
+    }
+
+    let mut out = String::with_capacity(input.len());
+    let mut escaped = false;
+
+    for ch in input.chars() {
+        if escaped {
+            out.push(ch);
+            escaped = false;
+        } else if ch == esc {
+            escaped = true;
+        } else {
+            out.push(ch);
+        }
+    }
+
+    if escaped {
+        out.push(esc);
+    }
+
+    Cow::Owned(out)
+}
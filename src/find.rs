@@ -0,0 +1,150 @@
+//! Locating delimiters in a [`str`] while respecting escapes.
+
+use crate::split::{check_escape, NonEscapedError, Pattern};
+use crate::util::Sorted;
+
+/// Returns the byte index of the first delimiter in `input` that is not escaped, or [`None`] if
+/// there is none.
+///
+/// An escape toggles whether the next char is taken literally, so a delimiter counts as escaped
+/// iff it is directly preceded by an odd run of escape chars.
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// assert_eq!(find::non_escaped(r"a\:b:c", '\\', [':'].into()), Some(4));
+/// assert_eq!(find::non_escaped(r"a\:b", '\\', [':'].into()), None);
+/// ```
+pub fn non_escaped<const N: usize>(
+    input: &str,
+    esc: char,
+    delims: Sorted<char, N>,
+) -> Option<usize> {
+    let mut escaped = false;
+
+    for (idx, ch) in input.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if ch == esc {
+            escaped = true;
+        } else if delims.contains(&ch) {
+            return Some(idx);
+        }
+    }
+
+    None
+}
+
+/// Returns an iterator over the byte indices of every unescaped match of `pat` in `input` together
+/// with the matched substring, the escape-aware analogue of [`str::match_indices`].
+///
+/// The matches are non-overlapping and reported left to right; a match is skipped if it is directly
+/// preceded by an odd run of escape chars. The escape chars themselves are never reported. Unlike
+/// the [`split`][crate::split] functions no allocation or splitting is done, the caller gets the raw
+/// positions to slice itself.
+///
+/// # Errors
+/// Returns an error if:
+/// - `pat` matches the escape char (see [`Pattern::matches_escape`], which predicate
+///   patterns never report)
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// let found: Vec<_> = find::match_indices(r"a\:b:c:d", '\\', ':').unwrap().collect();
+/// assert_eq!(found, [(4, ":"), (6, ":")]);
+/// ```
+pub fn match_indices<P: Pattern>(
+    input: &str,
+    esc: char,
+    pat: P,
+) -> Result<MatchIndices<'_, P>, NonEscapedError> {
+    check_escape(esc, &pat)?;
+
+    Ok(MatchIndices {
+        input,
+        esc,
+        pat,
+        pos: 0,
+        escaped: false,
+    })
+}
+
+/// Returns an iterator over the unescaped matches of `pat` in `input`, the escape-aware analogue of
+/// [`str::matches`].
+///
+/// See [`match_indices`] for the matching rules, this is the same iterator without the byte indices.
+///
+/// # Errors
+/// Returns an error if:
+/// - `pat` matches the escape char (see [`Pattern::matches_escape`], which predicate
+///   patterns never report)
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// let found: Vec<_> = find::matches(r"a\:b:c:d", '\\', ':').unwrap().collect();
+/// assert_eq!(found, [":", ":"]);
+/// ```
+pub fn matches<P: Pattern>(
+    input: &str,
+    esc: char,
+    pat: P,
+) -> Result<Matches<'_, P>, NonEscapedError> {
+    Ok(Matches {
+        inner: match_indices(input, esc, pat)?,
+    })
+}
+
+/// The iterator returned by [`match_indices`], yields the byte index and matched substring of every
+/// unescaped delimiter.
+#[derive(Debug, Clone)]
+pub struct MatchIndices<'a, P> {
+    input: &'a str,
+    esc: char,
+    pat: P,
+    pos: usize,
+    escaped: bool,
+}
+
+impl<'a, P: Pattern> Iterator for MatchIndices<'a, P> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(ch) = self.input[self.pos..].chars().next() {
+            let idx = self.pos;
+
+            if self.escaped {
+                self.escaped = false;
+                self.pos += ch.len_utf8();
+            } else if ch == self.esc {
+                self.escaped = true;
+                self.pos += ch.len_utf8();
+            } else if let Some(len) = self.pat.matches(self.input, idx) {
+                self.pos = idx + len;
+                return Some((idx, &self.input[idx..idx + len]));
+            } else {
+                self.pos += ch.len_utf8();
+            }
+        }
+
+        None
+    }
+}
+
+/// The iterator returned by [`matches`], yields the matched substring of every unescaped delimiter.
+#[derive(Debug, Clone)]
+pub struct Matches<'a, P> {
+    inner: MatchIndices<'a, P>,
+}
+
+impl<'a, P: Pattern> Iterator for Matches<'a, P> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, matched)| matched)
+    }
+}
@@ -68,6 +68,7 @@
 #![deny(unsafe_op_in_unsafe_fn)]
 
 use parse::{FromStrBack, FromStrFront};
+use split::Pattern;
 use util::Sorted;
 
 pub mod escape;
@@ -102,19 +103,16 @@ pub trait StrTools: util::sealed::Sealed {
     /// ```
     fn split_n_times<const N: usize>(&self, indices: &Sorted<usize, N>) -> ([&str; N], &str);
 
-    /// Splits a [`str`] by the given delimiters unless they are preceded by an escape.
-    /// Escapes before significant chars are removed, significant chars are the delimiters and the
-    /// escape itself. Trailing escapes are ignored as if followed by a non-significant char.
-    /// `delims` single char or an array of chars, which will be sorted, see the
-    /// [free version][free] of this function for more control over delimiter sorting.
+    /// Splits a [`str`] by the given delimiter [`Pattern`] unless a match is preceded by an escape.
+    /// Escapes before significant chars are removed, significant chars are a delimiter match and
+    /// the escape itself. Trailing escapes are ignored as if followed by a non-significant char.
+    /// `pat` can be a single char, an array or slice of chars, a `&str` or a `FnMut(char) -> bool`
+    /// predicate, see the [free version][free] of this function for the same behavior.
     ///
     /// # Errors
     /// Returns an error if:
-    /// - `esc == delim`
-    ///
-    /// # Complexity
-    /// This algorithm requires `O(n * max(log m, 1))` time where `n` is the length of the input
-    /// string and `m` is the length of the delimiters.
+    /// - `pat` matches the escape char (see [`Pattern::matches_escape`], which predicate
+    ///   patterns never report)
     ///
     /// # Allocation
     /// If no escapes are encountered in a part, no allocations are done and the part is borrowed,
@@ -135,26 +133,71 @@ pub trait StrTools: util::sealed::Sealed {
     /// # }
     /// ```
     ///
+    /// [`NonEscapedSanitize`][split::NonEscapedSanitize] is a [`DoubleEndedIterator`], so
+    /// `next` and `next_back` can be interleaved on the same iterator and still meet in the middle
+    /// correctly, even across escape runs of differing parity:
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use strtools::StrTools;
+    ///
+    /// let value = r"a:b\:c:d\\:e:f";
+    /// let mut iter = value.split_non_escaped_sanitize('\\', ':')?;
+    ///
+    /// assert_eq!(iter.next().as_deref(), Some("a"));
+    /// assert_eq!(iter.next_back().as_deref(), Some("f"));
+    /// assert_eq!(iter.next().as_deref(), Some("b:c"));
+    /// assert_eq!(iter.next_back().as_deref(), Some("e"));
+    /// // the remaining part had an escaped escape (odd run skipped, even run split) before it
+    /// assert_eq!(iter.next().as_deref(), Some(r"d\"));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.next_back(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// `pat` can also be a multi-char `&str` delimiter; the escape still only guards the first
+    /// char of a match:
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use strtools::StrTools;
+    ///
+    /// let parts: Vec<_> = r"a::b\::c::d".split_non_escaped_sanitize('\\', "::")?.collect();
+    ///
+    /// assert_eq!(parts, ["a", "b::c", "d"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// or a `&[char]` slice, for delimiter sets that aren't known at compile time:
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use strtools::StrTools;
+    ///
+    /// let delims: &[char] = &[' ', ','];
+    /// let parts: Vec<_> = "foo bar,baz".split_non_escaped_sanitize('\\', delims)?.collect();
+    ///
+    /// assert_eq!(parts, ["foo", "bar", "baz"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
     /// [free]: split::non_escaped_sanitize
-    fn split_non_escaped_sanitize<D: Into<Sorted<char, N>>, const N: usize>(
+    fn split_non_escaped_sanitize<P: Pattern>(
         &self,
         esc: char,
-        delims: D,
-    ) -> Result<split::NonEscapedSanitize<'_, N>, split::NonEscapedError>;
+        pat: P,
+    ) -> Result<split::NonEscapedSanitize<'_, P>, split::NonEscapedError>;
 
-    /// Splits a [`str`] by the given delimiters unless they are preceded by an escape.
-    /// Escapes before significant chars are removed, significant chars are the delimiters and the
-    /// escape itself. Trailing escapes are ignored as if followed by a non-significant char.
-    /// `delims` single char or an array of chars, which will be sorted, see the
-    /// [free version][free] of this function for more control over delimiter sorting.
+    /// Splits a [`str`] by the given delimiter [`Pattern`] unless a match is preceded by an escape.
+    /// Escapes are not removed, the parts are borrowed from the input with their escapes intact.
+    /// Trailing escapes are ignored as if followed by a non-significant char.
+    /// `pat` can be a single char, an array or slice of chars, a `&str` or a `FnMut(char) -> bool`
+    /// predicate, see the [free version][free] of this function for the same behavior.
     ///
     /// # Errors
     /// Returns an error if:
-    /// - `esc == delim`
-    ///
-    /// # Complexity
-    /// This algorithm requires `O(n * max(log m, 1))` time where `n` is the length of the input
-    /// string and `m` is the length of the delimiters.
+    /// - `pat` matches the escape char (see [`Pattern::matches_escape`], which predicate
+    ///   patterns never report)
     ///
     /// # Allocation
     /// No allocations are done.
@@ -173,12 +216,359 @@ pub trait StrTools: util::sealed::Sealed {
     /// # }
     /// ```
     ///
+    /// [`NonEscaped`][split::NonEscaped] is a [`DoubleEndedIterator`], so `next` and `next_back`
+    /// can be interleaved on the same iterator and still meet in the middle correctly, even across
+    /// escape runs of differing parity:
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use strtools::StrTools;
+    ///
+    /// let value = r"a:b\:c:d\\:e:f";
+    /// let mut iter = value.split_non_escaped('\\', ':')?;
+    ///
+    /// assert_eq!(iter.next(), Some("a"));
+    /// assert_eq!(iter.next_back(), Some("f"));
+    /// assert_eq!(iter.next(), Some(r"b\:c"));
+    /// assert_eq!(iter.next_back(), Some("e"));
+    /// // the remaining part had an escaped escape (odd run skipped, even run split) before it
+    /// assert_eq!(iter.next(), Some(r"d\\"));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.next_back(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// `pat` can also be a `FnMut(char) -> bool` predicate, e.g. to split on any digit:
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use strtools::StrTools;
+    ///
+    /// let parts: Vec<_> = r"a1b2\3c".split_non_escaped('\\', |c: char| c.is_ascii_digit())?.collect();
+    ///
+    /// assert_eq!(parts, ["a", "b", r"\3c"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
     /// [free]: split::non_escaped
-    fn split_non_escaped<D: Into<Sorted<char, N>>, const N: usize>(
+    fn split_non_escaped<P: Pattern>(
+        &self,
+        esc: char,
+        pat: P,
+    ) -> Result<split::NonEscaped<'_, P>, split::NonEscapedError>;
+
+    /// Behaves like [`split_non_escaped_sanitize`][StrTools::split_non_escaped_sanitize] but yields
+    /// the parts starting from the end of the input.
+    ///
+    /// The [`NonEscapedSanitize`][split::NonEscapedSanitize] iterator itself is a
+    /// [`DoubleEndedIterator`], this is simply the reversed wrapper for convenience. Because the
+    /// escaped-ness of a delimiter depends on the chars before it, the reverse scan counts the
+    /// parity of the preceding escape run rather than scanning left-to-right. This requires `pat`
+    /// to be a [`DoubleEndedPattern`][split::DoubleEndedPattern]: a self-overlapping `&str`
+    /// delimiter has no well-defined rightmost match, so `&str` patterns are not accepted here.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `pat` matches the escape char (see [`Pattern::matches_escape`], which predicate
+    ///   patterns never report)
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use strtools::StrTools;
+    ///
+    /// let value = r"Part0:Part1:Part2\:StillPart2";
+    /// let parts: Vec<_> = value.rsplit_non_escaped_sanitize('\\', ':')?.collect();
+    ///
+    /// assert_eq!(parts, ["Part2:StillPart2", "Part1", "Part0"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [free]: split::rnon_escaped_sanitize
+    fn rsplit_non_escaped_sanitize<P: split::DoubleEndedPattern>(
         &self,
         esc: char,
-        delims: D,
-    ) -> Result<split::NonEscaped<'_, N>, split::NonEscapedError>;
+        pat: P,
+    ) -> Result<split::RNonEscapedSanitize<'_, P>, split::NonEscapedError>;
+
+    /// Behaves like [`split_non_escaped`][StrTools::split_non_escaped] but yields the parts
+    /// starting from the end of the input.
+    ///
+    /// The [`NonEscaped`][split::NonEscaped] iterator itself is a [`DoubleEndedIterator`], this is
+    /// simply the reversed wrapper for convenience. Because the escaped-ness of a delimiter depends
+    /// on the chars before it, the reverse scan counts the parity of the preceding escape run
+    /// rather than scanning left-to-right. This requires `pat` to be a
+    /// [`DoubleEndedPattern`][split::DoubleEndedPattern]: a self-overlapping `&str` delimiter has no
+    /// well-defined rightmost match, so `&str` patterns are not accepted here.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `pat` matches the escape char (see [`Pattern::matches_escape`], which predicate
+    ///   patterns never report)
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use strtools::StrTools;
+    ///
+    /// let value = r"Part0:Part1:Part2\:StillPart2";
+    /// let parts: Vec<_> = value.rsplit_non_escaped('\\', ':')?.collect();
+    ///
+    /// assert_eq!(parts, [r"Part2\:StillPart2", "Part1", "Part0"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [free]: split::rnon_escaped
+    fn rsplit_non_escaped<P: split::DoubleEndedPattern>(
+        &self,
+        esc: char,
+        pat: P,
+    ) -> Result<split::RNonEscaped<'_, P>, split::NonEscapedError>;
+
+    /// Behaves like [`split_non_escaped`][StrTools::split_non_escaped] but yields at most `n`
+    /// parts, the last of which is the unsplit remainder (including any further unescaped
+    /// delimiters verbatim).
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `pat` matches the escape char (see [`Pattern::matches_escape`], which predicate
+    ///   patterns never report)
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use strtools::StrTools;
+    ///
+    /// // a `<rule>/<replace>/<flags>` input where the replacement keeps its own separators
+    /// let value = r"a\/b/x/y/z/gu";
+    /// let parts: Vec<_> = value.splitn_non_escaped_sanitize('\\', '/', 3)?.collect();
+    ///
+    /// assert_eq!(parts, ["a/b", "x", "y/z/gu"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [free]: split::splitn_non_escaped_sanitize
+    fn splitn_non_escaped_sanitize<P: Pattern>(
+        &self,
+        esc: char,
+        pat: P,
+        n: usize,
+    ) -> Result<split::SplitNSanitize<'_, P>, split::NonEscapedError>;
+
+    /// Behaves like [`split_non_escaped`][StrTools::split_non_escaped] but yields at most `n`
+    /// parts, the last of which is the unsplit remainder (including any further unescaped
+    /// delimiters verbatim).
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `pat` matches the escape char (see [`Pattern::matches_escape`], which predicate
+    ///   patterns never report)
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use strtools::StrTools;
+    ///
+    /// let value = "a:b:c:d";
+    /// let parts: Vec<_> = value.splitn_non_escaped('\\', ':', 2)?.collect();
+    ///
+    /// assert_eq!(parts, ["a", "b:c:d"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [free]: split::splitn_non_escaped
+    fn splitn_non_escaped<P: Pattern>(
+        &self,
+        esc: char,
+        pat: P,
+        n: usize,
+    ) -> Result<split::SplitN<'_, P>, split::NonEscapedError>;
+
+    /// Behaves like [`splitn_non_escaped_sanitize`][StrTools::splitn_non_escaped_sanitize] but
+    /// counts the `n` parts from the end, so the last part is the unsplit beginning. `pat` must be
+    /// a [`DoubleEndedPattern`][split::DoubleEndedPattern] for the same reason as
+    /// [`rsplit_non_escaped_sanitize`][StrTools::rsplit_non_escaped_sanitize]: the reverse scan
+    /// needs a well-defined rightmost match, which a self-overlapping `&str` delimiter doesn't have.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `pat` matches the escape char (see [`Pattern::matches_escape`], which predicate
+    ///   patterns never report)
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use strtools::StrTools;
+    ///
+    /// let value = r"a/b/c\/d/gu";
+    /// let parts: Vec<_> = value.rsplitn_non_escaped_sanitize('\\', '/', 2)?.collect();
+    ///
+    /// assert_eq!(parts, ["gu", "a/b/c/d"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [free]: split::rsplitn_non_escaped_sanitize
+    fn rsplitn_non_escaped_sanitize<P: split::DoubleEndedPattern>(
+        &self,
+        esc: char,
+        pat: P,
+        n: usize,
+    ) -> Result<split::RSplitNSanitize<'_, P>, split::NonEscapedError>;
+
+    /// Behaves like [`splitn_non_escaped`][StrTools::splitn_non_escaped] but counts the `n` parts
+    /// from the end, so the last part is the unsplit beginning. `pat` must be a
+    /// [`DoubleEndedPattern`][split::DoubleEndedPattern] for the same reason as
+    /// [`rsplit_non_escaped`][StrTools::rsplit_non_escaped]: the reverse scan needs a well-defined
+    /// rightmost match, which a self-overlapping `&str` delimiter doesn't have.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `pat` matches the escape char (see [`Pattern::matches_escape`], which predicate
+    ///   patterns never report)
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use strtools::StrTools;
+    ///
+    /// let value = "a:b:c:d";
+    /// let parts: Vec<_> = value.rsplitn_non_escaped('\\', ':', 2)?.collect();
+    ///
+    /// assert_eq!(parts, ["d", "a:b:c"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [free]: split::rsplitn_non_escaped
+    fn rsplitn_non_escaped<P: split::DoubleEndedPattern>(
+        &self,
+        esc: char,
+        pat: P,
+        n: usize,
+    ) -> Result<split::RSplitN<'_, P>, split::NonEscapedError>;
+
+    /// Behaves like [`split_non_escaped`][StrTools::split_non_escaped] but keeps the matched
+    /// delimiter attached to the end of the part it terminates, like [`str::split_inclusive`]. No
+    /// trailing empty part is produced if the input ends in an unescaped match.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `pat` matches the escape char (see [`Pattern::matches_escape`], which predicate
+    ///   patterns never report)
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use strtools::StrTools;
+    ///
+    /// let value = r"Part0:Part1\:StillPart1:Part2";
+    /// let parts: Vec<_> = value.split_inclusive_non_escaped('\\', ':')?.collect();
+    ///
+    /// assert_eq!(parts, [r"Part0:", r"Part1\:StillPart1:", "Part2"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [free]: split::split_inclusive_non_escaped
+    fn split_inclusive_non_escaped<P: Pattern>(
+        &self,
+        esc: char,
+        pat: P,
+    ) -> Result<split::SplitInclusive<'_, P>, split::NonEscapedError>;
+
+    /// Behaves like [`split_non_escaped_sanitize`][StrTools::split_non_escaped_sanitize] but keeps
+    /// the matched delimiter attached to the end of the part it terminates, like
+    /// [`str::split_inclusive`]. The escapes inside each part are still removed, only the retained
+    /// delimiter is left intact.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `pat` matches the escape char (see [`Pattern::matches_escape`], which predicate
+    ///   patterns never report)
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use strtools::StrTools;
+    ///
+    /// let value = r"Part0:Part1\:StillPart1:Part2";
+    /// let parts: Vec<_> = value.split_inclusive_non_escaped_sanitize('\\', ':')?.collect();
+    ///
+    /// assert_eq!(parts, ["Part0:", "Part1:StillPart1:", "Part2"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [free]: split::split_inclusive_non_escaped_sanitize
+    fn split_inclusive_non_escaped_sanitize<P: Pattern>(
+        &self,
+        esc: char,
+        pat: P,
+    ) -> Result<split::SplitInclusiveSanitize<'_, P>, split::NonEscapedError>;
+
+    /// Behaves like [`split_non_escaped`][StrTools::split_non_escaped] but, like
+    /// [`str::split_terminator`], treats the delimiter as a terminator and suppresses the trailing
+    /// empty part when the input ends in an unescaped match.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `pat` matches the escape char (see [`Pattern::matches_escape`], which predicate
+    ///   patterns never report)
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use strtools::StrTools;
+    ///
+    /// let value = r"Part0:Part1\::";
+    /// let parts: Vec<_> = value.split_terminator_non_escaped('\\', ':')?.collect();
+    ///
+    /// // the trailing separator does not produce an empty part, but the escaped one is kept
+    /// assert_eq!(parts, [r"Part0", r"Part1\:"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [free]: split::split_terminator_non_escaped
+    fn split_terminator_non_escaped<P: Pattern>(
+        &self,
+        esc: char,
+        pat: P,
+    ) -> Result<split::SplitTerminator<'_, P>, split::NonEscapedError>;
+
+    /// Behaves like [`split_non_escaped_sanitize`][StrTools::split_non_escaped_sanitize] but, like
+    /// [`str::split_terminator`], treats the delimiter as a terminator and suppresses the trailing
+    /// empty part when the input ends in an unescaped match.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `pat` matches the escape char (see [`Pattern::matches_escape`], which predicate
+    ///   patterns never report)
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use strtools::StrTools;
+    ///
+    /// let value = r"Part0:Part1\::";
+    /// let parts: Vec<_> = value.split_terminator_non_escaped_sanitize('\\', ':')?.collect();
+    ///
+    /// assert_eq!(parts, ["Part0", "Part1:"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [free]: split::split_terminator_non_escaped_sanitize
+    fn split_terminator_non_escaped_sanitize<P: Pattern>(
+        &self,
+        esc: char,
+        pat: P,
+    ) -> Result<split::SplitTerminatorSanitize<'_, P>, split::NonEscapedError>;
 
     /// Attempts to parse `T` from the beginning of the [`str`], returns the rest of the `input` and
     /// `T` if parsing succeeded.
@@ -220,20 +610,104 @@ impl StrTools for str {
         split::n_times(self, indices)
     }
 
-    fn split_non_escaped_sanitize<D: Into<Sorted<char, N>>, const N: usize>(
+    fn split_non_escaped_sanitize<P: Pattern>(
+        &self,
+        esc: char,
+        pat: P,
+    ) -> Result<split::NonEscapedSanitize<'_, P>, split::NonEscapedError> {
+        split::non_escaped_sanitize(self, esc, pat)
+    }
+
+    fn split_non_escaped<P: Pattern>(
+        &self,
+        esc: char,
+        pat: P,
+    ) -> Result<split::NonEscaped<'_, P>, split::NonEscapedError> {
+        split::non_escaped(self, esc, pat)
+    }
+
+    fn rsplit_non_escaped_sanitize<P: split::DoubleEndedPattern>(
+        &self,
+        esc: char,
+        pat: P,
+    ) -> Result<split::RNonEscapedSanitize<'_, P>, split::NonEscapedError> {
+        split::rnon_escaped_sanitize(self, esc, pat)
+    }
+
+    fn rsplit_non_escaped<P: split::DoubleEndedPattern>(
+        &self,
+        esc: char,
+        pat: P,
+    ) -> Result<split::RNonEscaped<'_, P>, split::NonEscapedError> {
+        split::rnon_escaped(self, esc, pat)
+    }
+
+    fn splitn_non_escaped_sanitize<P: Pattern>(
+        &self,
+        esc: char,
+        pat: P,
+        n: usize,
+    ) -> Result<split::SplitNSanitize<'_, P>, split::NonEscapedError> {
+        split::splitn_non_escaped_sanitize(self, esc, pat, n)
+    }
+
+    fn splitn_non_escaped<P: Pattern>(
+        &self,
+        esc: char,
+        pat: P,
+        n: usize,
+    ) -> Result<split::SplitN<'_, P>, split::NonEscapedError> {
+        split::splitn_non_escaped(self, esc, pat, n)
+    }
+
+    fn rsplitn_non_escaped_sanitize<P: split::DoubleEndedPattern>(
+        &self,
+        esc: char,
+        pat: P,
+        n: usize,
+    ) -> Result<split::RSplitNSanitize<'_, P>, split::NonEscapedError> {
+        split::rsplitn_non_escaped_sanitize(self, esc, pat, n)
+    }
+
+    fn rsplitn_non_escaped<P: split::DoubleEndedPattern>(
+        &self,
+        esc: char,
+        pat: P,
+        n: usize,
+    ) -> Result<split::RSplitN<'_, P>, split::NonEscapedError> {
+        split::rsplitn_non_escaped(self, esc, pat, n)
+    }
+
+    fn split_inclusive_non_escaped<P: Pattern>(
+        &self,
+        esc: char,
+        pat: P,
+    ) -> Result<split::SplitInclusive<'_, P>, split::NonEscapedError> {
+        split::split_inclusive_non_escaped(self, esc, pat)
+    }
+
+    fn split_inclusive_non_escaped_sanitize<P: Pattern>(
+        &self,
+        esc: char,
+        pat: P,
+    ) -> Result<split::SplitInclusiveSanitize<'_, P>, split::NonEscapedError> {
+        split::split_inclusive_non_escaped_sanitize(self, esc, pat)
+    }
+
+    fn split_terminator_non_escaped<P: Pattern>(
         &self,
         esc: char,
-        delims: D,
-    ) -> Result<split::NonEscapedSanitize<'_, N>, split::NonEscapedError> {
-        split::non_escaped_sanitize(self, esc, delims.into())
+        pat: P,
+    ) -> Result<split::SplitTerminator<'_, P>, split::NonEscapedError> {
+        split::split_terminator_non_escaped(self, esc, pat)
     }
 
-    fn split_non_escaped<D: Into<Sorted<char, N>>, const N: usize>(
+    fn split_terminator_non_escaped_sanitize<P: Pattern>(
         &self,
         esc: char,
-        delims: D,
-    ) -> Result<split::NonEscaped<'_, N>, split::NonEscapedError> {
-        split::non_escaped(self, esc, delims.into())
+        pat: P,
+    ) -> Result<split::SplitTerminatorSanitize<'_, P>, split::NonEscapedError> {
+        split::split_terminator_non_escaped_sanitize(self, esc, pat)
     }
 
     fn parse_front<T: FromStrFront>(&self) -> Result<(T, &str), T::Error> {
@@ -0,0 +1,101 @@
+//! Traits for parsing a value from either end of a [`str`] while returning the unconsumed rest.
+//!
+//! These are used by [`StrTools::parse_front`](crate::StrTools::parse_front) and
+//! [`StrTools::parse_back`](crate::StrTools::parse_back).
+
+/// The error returned by the integer implementations of [`FromStrFront`] and [`FromStrBack`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// No valid representation was found at the expected end of the input.
+    NoValue,
+    /// A representation was found but it did not fit into the target type.
+    Overflow,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::NoValue => f.write_str("no valid value was found"),
+            ParseError::Overflow => f.write_str("the value did not fit into the target type"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Attempts to parse `Self` from the front of a [`str`], returning the parsed value together with
+/// the unconsumed rest of the input.
+pub trait FromStrFront: Sized {
+    /// The error returned if parsing fails.
+    type Error = ParseError;
+
+    /// Parses `Self` from the front of `input`.
+    ///
+    /// # Errors
+    /// Returns an error if `input` does not start with a valid representation of `Self`.
+    fn from_str_front(input: &str) -> Result<(Self, &str), Self::Error>;
+}
+
+/// Attempts to parse `Self` from the back of a [`str`], returning the parsed value together with
+/// the unconsumed rest of the input.
+pub trait FromStrBack: Sized {
+    /// The error returned if parsing fails.
+    type Error = ParseError;
+
+    /// Parses `Self` from the back of `input`.
+    ///
+    /// # Errors
+    /// Returns an error if `input` does not end with a valid representation of `Self`.
+    fn from_str_back(input: &str) -> Result<(Self, &str), Self::Error>;
+}
+
+macro impl_int($($ty:ty),* $(,)?) {
+    $(
+        impl FromStrFront for $ty {
+            fn from_str_front(input: &str) -> Result<(Self, &str), Self::Error> {
+                let bytes = input.as_bytes();
+                let mut end = 0;
+
+                if matches!(bytes.first(), Some(b'+' | b'-')) {
+                    end += 1;
+                }
+
+                let digits = end;
+                while matches!(bytes.get(end), Some(b'0'..=b'9')) {
+                    end += 1;
+                }
+
+                if end == digits {
+                    return Err(ParseError::NoValue);
+                }
+
+                let value = input[..end].parse().map_err(|_| ParseError::Overflow)?;
+                Ok((value, &input[end..]))
+            }
+        }
+
+        impl FromStrBack for $ty {
+            fn from_str_back(input: &str) -> Result<(Self, &str), Self::Error> {
+                let bytes = input.as_bytes();
+                let mut start = input.len();
+
+                while start > 0 && matches!(bytes[start - 1], b'0'..=b'9') {
+                    start -= 1;
+                }
+
+                if start == input.len() {
+                    return Err(ParseError::NoValue);
+                }
+
+                if start > 0 && matches!(bytes[start - 1], b'+' | b'-') {
+                    start -= 1;
+                }
+
+                let value = input[start..].parse().map_err(|_| ParseError::Overflow)?;
+                Ok((value, &input[..start]))
+            }
+        }
+    )*
+}
+
+impl_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
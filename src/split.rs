@@ -0,0 +1,771 @@
+//! Splitting of [`str`]s, both by byte index and escape-aware by delimiter pattern.
+
+use std::borrow::Cow;
+
+use crate::util::Sorted;
+
+/// A pattern describing how a delimiter is matched, the crate-local analogue of
+/// [`std::str::pattern::Pattern`].
+///
+/// It is implemented for a single [`char`], an array or slice of [`char`]s, a `&str` (for
+/// multi-char delimiters) and any `FnMut(char) -> bool` predicate, so the escape-aware split
+/// functions can accept all of them while keeping the escape handling in one place.
+///
+/// Implementations are expected to be pure: a match decision must depend only on `input` and `at`,
+/// not on how often [`matches`][Pattern::matches] has been called. The scan may query the same
+/// position more than once (the sanitizing split re-checks escaped positions) and double-ended
+/// iteration queries from both ends, so a call-count-dependent predicate would give inconsistent
+/// splits.
+pub trait Pattern {
+    /// Reports whether a delimiter matches in `input` starting at the byte index `at`, returning
+    /// the byte length of the match if it does.
+    ///
+    /// `at` is always on a char boundary of `input`. Returning `Some(0)` is not allowed as it would
+    /// not advance the scan.
+    fn matches(&mut self, input: &str, at: usize) -> Option<usize>;
+
+    /// Reports whether this pattern matches the lone escape char, which is rejected by the split
+    /// constructors as it would make the semantics ambiguous.
+    ///
+    /// This is checked without running [`matches`][Pattern::matches] (which would require `&mut`
+    /// and could not be called on a predicate here anyway), so the default reports `false`. This
+    /// means predicate patterns are never rejected even if they would match the escape char;
+    /// escapes simply take precedence over a delimiter match at their position.
+    fn matches_escape(&self, _esc: char) -> bool {
+        false
+    }
+}
+
+/// A marker for [`Pattern`]s that match a single char and can therefore be searched from either
+/// end without ambiguity, the analogue of [`std::str::pattern::DoubleEndedSearcher`].
+///
+/// This is the bound under which the split iterators implement [`DoubleEndedIterator`], and under
+/// which the `r`-prefixed reverse-scan constructors ([`rnon_escaped`] and friends) are available at
+/// all. Reverse scanning finds the *rightmost* match by walking backward, which only agrees with
+/// the forward partition for patterns whose matches can't overlap themselves; a self-overlapping
+/// `&str` delimiter (e.g. `"aa"` in `"aaa"`) can make the rightmost-starting match diverge from the
+/// split point a forward scan would have chosen. Since `&str` is the only [`Pattern`] that can
+/// self-overlap, it is the only one that isn't a [`DoubleEndedPattern`], so it is rejected by the
+/// reverse constructors entirely rather than silently yielding parts that don't agree with their
+/// forward counterparts.
+pub trait DoubleEndedPattern: Pattern {}
+
+/// Matches the char at `at` against `pred`, returning its byte length on success.
+fn match_char(input: &str, at: usize, pred: impl FnOnce(char) -> bool) -> Option<usize> {
+    let ch = input[at..].chars().next()?;
+    pred(ch).then(|| ch.len_utf8())
+}
+
+impl Pattern for char {
+    fn matches(&mut self, input: &str, at: usize) -> Option<usize> {
+        match_char(input, at, |ch| ch == *self)
+    }
+
+    fn matches_escape(&self, esc: char) -> bool {
+        *self == esc
+    }
+}
+
+impl DoubleEndedPattern for char {}
+
+impl<const N: usize> Pattern for [char; N] {
+    fn matches(&mut self, input: &str, at: usize) -> Option<usize> {
+        match_char(input, at, |ch| self.contains(&ch))
+    }
+
+    fn matches_escape(&self, esc: char) -> bool {
+        self.contains(&esc)
+    }
+}
+
+impl<const N: usize> DoubleEndedPattern for [char; N] {}
+
+impl Pattern for &[char] {
+    fn matches(&mut self, input: &str, at: usize) -> Option<usize> {
+        match_char(input, at, |ch| self.contains(&ch))
+    }
+
+    fn matches_escape(&self, esc: char) -> bool {
+        self.contains(&esc)
+    }
+}
+
+impl DoubleEndedPattern for &[char] {}
+
+impl Pattern for &str {
+    fn matches(&mut self, input: &str, at: usize) -> Option<usize> {
+        (!self.is_empty() && input[at..].starts_with(*self)).then_some(self.len())
+    }
+
+    fn matches_escape(&self, esc: char) -> bool {
+        let mut buf = [0; 4];
+        *self == esc.encode_utf8(&mut buf)
+    }
+}
+
+impl<F: FnMut(char) -> bool> Pattern for F {
+    fn matches(&mut self, input: &str, at: usize) -> Option<usize> {
+        match_char(input, at, self)
+    }
+}
+
+impl<F: FnMut(char) -> bool> DoubleEndedPattern for F {}
+
+/// The error returned when an escape-aware split was requested with a pattern that also matches the
+/// escape char.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonEscapedError;
+
+impl std::fmt::Display for NonEscapedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("the escape char must not be matched by the delimiter pattern")
+    }
+}
+
+impl std::error::Error for NonEscapedError {}
+
+/// Splits `input` at the given byte `indices`, see [`StrTools::split_n_times`].
+///
+/// # Panics
+/// Panics if the last index is out of bounds or does not lie on a char boundary.
+///
+/// [`StrTools::split_n_times`]: crate::StrTools::split_n_times
+pub fn n_times<'a, const N: usize>(
+    input: &'a str,
+    indices: &Sorted<usize, N>,
+) -> ([&'a str; N], &'a str) {
+    let mut parts = [""; N];
+    let mut prev = 0;
+
+    for (part, &idx) in parts.iter_mut().zip(indices.iter()) {
+        *part = &input[prev..idx];
+        prev = idx;
+    }
+
+    (parts, &input[prev..])
+}
+
+/// Returns an error if `pat` matches the lone escape char, which would make the semantics
+/// ambiguous.
+pub(crate) fn check_escape<P: Pattern>(esc: char, pat: &P) -> Result<(), NonEscapedError> {
+    if pat.matches_escape(esc) {
+        Err(NonEscapedError)
+    } else {
+        Ok(())
+    }
+}
+
+/// Removes the escapes from `part` that guard a delimiter match or another escape, keeping escapes
+/// before insignificant chars (and a trailing escape) verbatim.
+///
+/// Borrows `part` if it contains no escape at all.
+fn sanitize<'a, P: Pattern>(part: &'a str, esc: char, pat: &mut P) -> Cow<'a, str> {
+    if !part.contains(esc) {
+        return Cow::Borrowed(part);
+    }
+
+    let mut out = String::with_capacity(part.len());
+    let mut escaped = false;
+
+    for (idx, ch) in part.char_indices() {
+        if escaped {
+            if ch != esc && pat.matches(part, idx).is_none() {
+                out.push(esc);
+            }
+            out.push(ch);
+            escaped = false;
+        } else if ch == esc {
+            escaped = true;
+        } else {
+            out.push(ch);
+        }
+    }
+
+    if escaped {
+        out.push(esc);
+    }
+
+    Cow::Owned(out)
+}
+
+/// Splits `input` by the given pattern unless a match is escaped, see
+/// [`StrTools::split_non_escaped`].
+///
+/// # Errors
+/// Returns an error if `pat` matches the escape char.
+///
+/// [`StrTools::split_non_escaped`]: crate::StrTools::split_non_escaped
+pub fn non_escaped<P: Pattern>(
+    input: &str,
+    esc: char,
+    pat: P,
+) -> Result<NonEscaped<'_, P>, NonEscapedError> {
+    check_escape(esc, &pat)?;
+
+    Ok(NonEscaped {
+        remainder: Some(input),
+        esc,
+        pat,
+    })
+}
+
+/// Splits `input` by the given pattern unless a match is escaped, removing the escapes from each
+/// part, see [`StrTools::split_non_escaped_sanitize`].
+///
+/// # Errors
+/// Returns an error if `pat` matches the escape char.
+///
+/// [`StrTools::split_non_escaped_sanitize`]: crate::StrTools::split_non_escaped_sanitize
+pub fn non_escaped_sanitize<P: Pattern>(
+    input: &str,
+    esc: char,
+    pat: P,
+) -> Result<NonEscapedSanitize<'_, P>, NonEscapedError> {
+    Ok(NonEscapedSanitize {
+        inner: non_escaped(input, esc, pat)?,
+    })
+}
+
+/// The iterator returned by [`non_escaped`], yields the borrowed parts with their escapes intact.
+#[derive(Debug, Clone)]
+pub struct NonEscaped<'a, P> {
+    remainder: Option<&'a str>,
+    esc: char,
+    pat: P,
+}
+
+impl<'a, P: Pattern> NonEscaped<'a, P> {
+    /// Cuts off and returns the next part from the front of the remainder.
+    fn scan_front(&mut self) -> Option<&'a str> {
+        let rest = self.remainder?;
+        let mut escaped = false;
+
+        for (idx, ch) in rest.char_indices() {
+            if escaped {
+                escaped = false;
+            } else if ch == self.esc {
+                escaped = true;
+            } else if let Some(len) = self.pat.matches(rest, idx) {
+                self.remainder = Some(&rest[idx + len..]);
+                return Some(&rest[..idx]);
+            }
+        }
+
+        self.remainder = None;
+        Some(rest)
+    }
+
+    /// Takes the whole unscanned remainder as a single part, leaving the iterator empty.
+    fn take_remainder(&mut self) -> Option<&'a str> {
+        self.remainder.take()
+    }
+
+    /// Cuts off and returns the next part from the front of the remainder, keeping the matched
+    /// delimiter attached to its end.
+    ///
+    /// An empty part produced by a delimiter at the very end of the input is not emitted, mirroring
+    /// [`str::split_inclusive`].
+    fn scan_front_inclusive(&mut self) -> Option<&'a str> {
+        let rest = self.remainder?;
+        if rest.is_empty() {
+            self.remainder = None;
+            return None;
+        }
+
+        // reuse the plain scan and re-attach the consumed delimiter; the new remainder is a suffix
+        // of `rest`, so its length gives us the byte offset just past the match (or the whole of
+        // `rest` if none was found and the remainder was cleared)
+        self.scan_front()?;
+        let end = self.remainder.map_or(rest.len(), |tail| rest.len() - tail.len());
+
+        Some(&rest[..end])
+    }
+
+    /// Cuts off and returns the next part from the front of the remainder, suppressing the single
+    /// trailing empty part produced by a delimiter at the very end of the input, mirroring
+    /// [`str::split_terminator`].
+    fn scan_front_terminator(&mut self) -> Option<&'a str> {
+        let part = self.scan_front()?;
+        (!part.is_empty() || self.remainder.is_some()).then_some(part)
+    }
+
+    /// Cuts off and returns the next part from the back of the remainder.
+    ///
+    /// The rightmost match is found by scanning backward; its escaped-ness is the parity of the
+    /// immediately preceding run of escape chars (even splits, odd is literal, a match at index 0
+    /// always splits). Each escape char is consumed by the run it belongs to, keeping this `O(n)`.
+    fn scan_back(&mut self) -> Option<&'a str> {
+        let rest = self.remainder?;
+        let mut iter = rest.char_indices().rev().peekable();
+
+        while let Some((idx, ch)) = iter.next() {
+            if ch == self.esc {
+                continue;
+            }
+
+            if let Some(len) = self.pat.matches(rest, idx) {
+                let mut run = 0;
+                while iter.next_if(|&(_, pch)| pch == self.esc).is_some() {
+                    run += 1;
+                }
+
+                if run % 2 == 0 {
+                    self.remainder = Some(&rest[..idx]);
+                    return Some(&rest[idx + len..]);
+                }
+            }
+        }
+
+        self.remainder = None;
+        Some(rest)
+    }
+}
+
+impl<'a, P: Pattern> Iterator for NonEscaped<'a, P> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.scan_front()
+    }
+}
+
+impl<'a, P: DoubleEndedPattern> DoubleEndedIterator for NonEscaped<'a, P> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.scan_back()
+    }
+}
+
+/// The iterator returned by [`non_escaped_sanitize`], yields the parts with their escapes removed.
+#[derive(Debug, Clone)]
+pub struct NonEscapedSanitize<'a, P> {
+    inner: NonEscaped<'a, P>,
+}
+
+impl<'a, P: Pattern> NonEscapedSanitize<'a, P> {
+    /// Cuts off, sanitizes and returns the next part from the front of the remainder.
+    fn scan_front(&mut self) -> Option<Cow<'a, str>> {
+        let part = self.inner.scan_front()?;
+        Some(sanitize(part, self.inner.esc, &mut self.inner.pat))
+    }
+
+    /// Cuts off, sanitizes and returns the next part from the back of the remainder.
+    fn scan_back(&mut self) -> Option<Cow<'a, str>> {
+        let part = self.inner.scan_back()?;
+        Some(sanitize(part, self.inner.esc, &mut self.inner.pat))
+    }
+
+    /// Takes the whole unscanned remainder as a single sanitized part, leaving the iterator empty.
+    fn take_remainder(&mut self) -> Option<Cow<'a, str>> {
+        let part = self.inner.take_remainder()?;
+        Some(sanitize(part, self.inner.esc, &mut self.inner.pat))
+    }
+
+    /// Cuts off, sanitizes and returns the next part from the front of the remainder, keeping the
+    /// matched delimiter attached to its end.
+    fn scan_front_inclusive(&mut self) -> Option<Cow<'a, str>> {
+        let part = self.inner.scan_front_inclusive()?;
+        Some(sanitize(part, self.inner.esc, &mut self.inner.pat))
+    }
+
+    /// Cuts off, sanitizes and returns the next part from the front of the remainder, suppressing
+    /// the single trailing empty part produced by a delimiter at the very end of the input.
+    fn scan_front_terminator(&mut self) -> Option<Cow<'a, str>> {
+        let part = self.inner.scan_front_terminator()?;
+        Some(sanitize(part, self.inner.esc, &mut self.inner.pat))
+    }
+}
+
+impl<'a, P: Pattern> Iterator for NonEscapedSanitize<'a, P> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.scan_front()
+    }
+}
+
+impl<'a, P: DoubleEndedPattern> DoubleEndedIterator for NonEscapedSanitize<'a, P> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.scan_back()
+    }
+}
+
+/// Splits `input` by the given pattern unless a match is escaped, yielding the parts starting from
+/// the end, see [`StrTools::rsplit_non_escaped`].
+///
+/// # Errors
+/// Returns an error if `pat` matches the escape char.
+///
+/// [`StrTools::rsplit_non_escaped`]: crate::StrTools::rsplit_non_escaped
+pub fn rnon_escaped<P: DoubleEndedPattern>(
+    input: &str,
+    esc: char,
+    pat: P,
+) -> Result<RNonEscaped<'_, P>, NonEscapedError> {
+    Ok(RNonEscaped {
+        inner: non_escaped(input, esc, pat)?,
+    })
+}
+
+/// Splits `input` by the given pattern unless a match is escaped, removing the escapes from each
+/// part and yielding the parts starting from the end, see
+/// [`StrTools::rsplit_non_escaped_sanitize`].
+///
+/// # Errors
+/// Returns an error if `pat` matches the escape char.
+///
+/// [`StrTools::rsplit_non_escaped_sanitize`]: crate::StrTools::rsplit_non_escaped_sanitize
+pub fn rnon_escaped_sanitize<P: DoubleEndedPattern>(
+    input: &str,
+    esc: char,
+    pat: P,
+) -> Result<RNonEscapedSanitize<'_, P>, NonEscapedError> {
+    Ok(RNonEscapedSanitize {
+        inner: non_escaped_sanitize(input, esc, pat)?,
+    })
+}
+
+/// The iterator returned by [`rnon_escaped`], yields the borrowed parts in reverse order.
+#[derive(Debug, Clone)]
+pub struct RNonEscaped<'a, P> {
+    inner: NonEscaped<'a, P>,
+}
+
+impl<'a, P: Pattern> Iterator for RNonEscaped<'a, P> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.scan_back()
+    }
+}
+
+impl<'a, P: DoubleEndedPattern> DoubleEndedIterator for RNonEscaped<'a, P> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.scan_front()
+    }
+}
+
+/// The iterator returned by [`rnon_escaped_sanitize`], yields the sanitized parts in reverse order.
+#[derive(Debug, Clone)]
+pub struct RNonEscapedSanitize<'a, P> {
+    inner: NonEscapedSanitize<'a, P>,
+}
+
+impl<'a, P: Pattern> Iterator for RNonEscapedSanitize<'a, P> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.scan_back()
+    }
+}
+
+impl<'a, P: DoubleEndedPattern> DoubleEndedIterator for RNonEscapedSanitize<'a, P> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.scan_front()
+    }
+}
+
+/// Splits `input` by the given pattern unless a match is escaped, into at most `n` parts with the
+/// last part holding the unsplit remainder, see [`StrTools::splitn_non_escaped`].
+///
+/// # Errors
+/// Returns an error if `pat` matches the escape char.
+///
+/// [`StrTools::splitn_non_escaped`]: crate::StrTools::splitn_non_escaped
+pub fn splitn_non_escaped<P: Pattern>(
+    input: &str,
+    esc: char,
+    pat: P,
+    n: usize,
+) -> Result<SplitN<'_, P>, NonEscapedError> {
+    Ok(SplitN {
+        inner: non_escaped(input, esc, pat)?,
+        n,
+    })
+}
+
+/// Splits `input` by the given pattern unless a match is escaped, into at most `n` parts with the
+/// last part holding the unsplit remainder, removing the escapes from each part, see
+/// [`StrTools::splitn_non_escaped_sanitize`].
+///
+/// # Errors
+/// Returns an error if `pat` matches the escape char.
+///
+/// [`StrTools::splitn_non_escaped_sanitize`]: crate::StrTools::splitn_non_escaped_sanitize
+pub fn splitn_non_escaped_sanitize<P: Pattern>(
+    input: &str,
+    esc: char,
+    pat: P,
+    n: usize,
+) -> Result<SplitNSanitize<'_, P>, NonEscapedError> {
+    Ok(SplitNSanitize {
+        inner: non_escaped_sanitize(input, esc, pat)?,
+        n,
+    })
+}
+
+/// Splits `input` by the given pattern unless a match is escaped, into at most `n` parts counted
+/// from the end with the last part holding the unsplit remainder, see
+/// [`StrTools::rsplitn_non_escaped`].
+///
+/// # Errors
+/// Returns an error if `pat` matches the escape char.
+///
+/// [`StrTools::rsplitn_non_escaped`]: crate::StrTools::rsplitn_non_escaped
+pub fn rsplitn_non_escaped<P: DoubleEndedPattern>(
+    input: &str,
+    esc: char,
+    pat: P,
+    n: usize,
+) -> Result<RSplitN<'_, P>, NonEscapedError> {
+    Ok(RSplitN {
+        inner: non_escaped(input, esc, pat)?,
+        n,
+    })
+}
+
+/// Splits `input` by the given pattern unless a match is escaped, into at most `n` parts counted
+/// from the end with the last part holding the unsplit remainder, removing the escapes from each
+/// part, see [`StrTools::rsplitn_non_escaped_sanitize`].
+///
+/// # Errors
+/// Returns an error if `pat` matches the escape char.
+///
+/// [`StrTools::rsplitn_non_escaped_sanitize`]: crate::StrTools::rsplitn_non_escaped_sanitize
+pub fn rsplitn_non_escaped_sanitize<P: DoubleEndedPattern>(
+    input: &str,
+    esc: char,
+    pat: P,
+    n: usize,
+) -> Result<RSplitNSanitize<'_, P>, NonEscapedError> {
+    Ok(RSplitNSanitize {
+        inner: non_escaped_sanitize(input, esc, pat)?,
+        n,
+    })
+}
+
+/// The iterator returned by [`splitn_non_escaped`], yields at most `n` borrowed parts.
+#[derive(Debug, Clone)]
+pub struct SplitN<'a, P> {
+    inner: NonEscaped<'a, P>,
+    n: usize,
+}
+
+impl<'a, P: Pattern> Iterator for SplitN<'a, P> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.n == 0 {
+            return None;
+        }
+
+        self.n -= 1;
+        if self.n == 0 {
+            self.inner.take_remainder()
+        } else {
+            self.inner.scan_front()
+        }
+    }
+}
+
+/// The iterator returned by [`splitn_non_escaped_sanitize`], yields at most `n` sanitized parts.
+#[derive(Debug, Clone)]
+pub struct SplitNSanitize<'a, P> {
+    inner: NonEscapedSanitize<'a, P>,
+    n: usize,
+}
+
+impl<'a, P: Pattern> Iterator for SplitNSanitize<'a, P> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.n == 0 {
+            return None;
+        }
+
+        self.n -= 1;
+        if self.n == 0 {
+            self.inner.take_remainder()
+        } else {
+            self.inner.scan_front()
+        }
+    }
+}
+
+/// The iterator returned by [`rsplitn_non_escaped`], yields at most `n` borrowed parts from the end.
+#[derive(Debug, Clone)]
+pub struct RSplitN<'a, P> {
+    inner: NonEscaped<'a, P>,
+    n: usize,
+}
+
+impl<'a, P: Pattern> Iterator for RSplitN<'a, P> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.n == 0 {
+            return None;
+        }
+
+        self.n -= 1;
+        if self.n == 0 {
+            self.inner.take_remainder()
+        } else {
+            self.inner.scan_back()
+        }
+    }
+}
+
+/// The iterator returned by [`rsplitn_non_escaped_sanitize`], yields at most `n` sanitized parts
+/// from the end.
+#[derive(Debug, Clone)]
+pub struct RSplitNSanitize<'a, P> {
+    inner: NonEscapedSanitize<'a, P>,
+    n: usize,
+}
+
+impl<'a, P: Pattern> Iterator for RSplitNSanitize<'a, P> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.n == 0 {
+            return None;
+        }
+
+        self.n -= 1;
+        if self.n == 0 {
+            self.inner.take_remainder()
+        } else {
+            self.inner.scan_back()
+        }
+    }
+}
+
+/// Splits `input` by the given pattern unless a match is escaped, keeping the matched delimiter
+/// attached to the end of the preceding part, see [`StrTools::split_inclusive_non_escaped`].
+///
+/// # Errors
+/// Returns an error if `pat` matches the escape char.
+///
+/// [`StrTools::split_inclusive_non_escaped`]: crate::StrTools::split_inclusive_non_escaped
+pub fn split_inclusive_non_escaped<P: Pattern>(
+    input: &str,
+    esc: char,
+    pat: P,
+) -> Result<SplitInclusive<'_, P>, NonEscapedError> {
+    Ok(SplitInclusive {
+        inner: non_escaped(input, esc, pat)?,
+    })
+}
+
+/// Splits `input` by the given pattern unless a match is escaped, keeping the matched delimiter
+/// attached to the end of the preceding part and removing the escapes from each part, see
+/// [`StrTools::split_inclusive_non_escaped_sanitize`].
+///
+/// # Errors
+/// Returns an error if `pat` matches the escape char.
+///
+/// [`StrTools::split_inclusive_non_escaped_sanitize`]: crate::StrTools::split_inclusive_non_escaped_sanitize
+pub fn split_inclusive_non_escaped_sanitize<P: Pattern>(
+    input: &str,
+    esc: char,
+    pat: P,
+) -> Result<SplitInclusiveSanitize<'_, P>, NonEscapedError> {
+    Ok(SplitInclusiveSanitize {
+        inner: non_escaped_sanitize(input, esc, pat)?,
+    })
+}
+
+/// Splits `input` by the given pattern unless a match is escaped, suppressing the trailing empty
+/// part if `input` ends in an unescaped match, see [`StrTools::split_terminator_non_escaped`].
+///
+/// # Errors
+/// Returns an error if `pat` matches the escape char.
+///
+/// [`StrTools::split_terminator_non_escaped`]: crate::StrTools::split_terminator_non_escaped
+pub fn split_terminator_non_escaped<P: Pattern>(
+    input: &str,
+    esc: char,
+    pat: P,
+) -> Result<SplitTerminator<'_, P>, NonEscapedError> {
+    Ok(SplitTerminator {
+        inner: non_escaped(input, esc, pat)?,
+    })
+}
+
+/// Splits `input` by the given pattern unless a match is escaped, suppressing the trailing empty
+/// part if `input` ends in an unescaped match and removing the escapes from each part, see
+/// [`StrTools::split_terminator_non_escaped_sanitize`].
+///
+/// # Errors
+/// Returns an error if `pat` matches the escape char.
+///
+/// [`StrTools::split_terminator_non_escaped_sanitize`]: crate::StrTools::split_terminator_non_escaped_sanitize
+pub fn split_terminator_non_escaped_sanitize<P: Pattern>(
+    input: &str,
+    esc: char,
+    pat: P,
+) -> Result<SplitTerminatorSanitize<'_, P>, NonEscapedError> {
+    Ok(SplitTerminatorSanitize {
+        inner: non_escaped_sanitize(input, esc, pat)?,
+    })
+}
+
+/// The iterator returned by [`split_inclusive_non_escaped`], yields the borrowed parts with the
+/// matched delimiter still attached to the end of each part it terminates.
+#[derive(Debug, Clone)]
+pub struct SplitInclusive<'a, P> {
+    inner: NonEscaped<'a, P>,
+}
+
+impl<'a, P: Pattern> Iterator for SplitInclusive<'a, P> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.scan_front_inclusive()
+    }
+}
+
+/// The iterator returned by [`split_inclusive_non_escaped_sanitize`], yields the sanitized parts
+/// with the matched delimiter still attached to the end of each part it terminates.
+#[derive(Debug, Clone)]
+pub struct SplitInclusiveSanitize<'a, P> {
+    inner: NonEscapedSanitize<'a, P>,
+}
+
+impl<'a, P: Pattern> Iterator for SplitInclusiveSanitize<'a, P> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.scan_front_inclusive()
+    }
+}
+
+/// The iterator returned by [`split_terminator_non_escaped`], yields the borrowed parts without a
+/// trailing empty part for a terminating match.
+#[derive(Debug, Clone)]
+pub struct SplitTerminator<'a, P> {
+    inner: NonEscaped<'a, P>,
+}
+
+impl<'a, P: Pattern> Iterator for SplitTerminator<'a, P> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.scan_front_terminator()
+    }
+}
+
+/// The iterator returned by [`split_terminator_non_escaped_sanitize`], yields the sanitized parts
+/// without a trailing empty part for a terminating match.
+#[derive(Debug, Clone)]
+pub struct SplitTerminatorSanitize<'a, P> {
+    inner: NonEscapedSanitize<'a, P>,
+}
+
+impl<'a, P: Pattern> Iterator for SplitTerminatorSanitize<'a, P> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.scan_front_terminator()
+    }
+}
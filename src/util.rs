@@ -0,0 +1,89 @@
+//! Various utility types used throughout this crate.
+
+use std::ops::Deref;
+
+pub(crate) mod sealed {
+    /// Prevents downstream implementations of [`StrTools`](crate::StrTools).
+    pub trait Sealed {}
+    impl Sealed for str {}
+}
+
+/// The error returned when an array could not be turned into a [`Sorted`] because it was not
+/// actually sorted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotSortedError;
+
+impl std::fmt::Display for NotSortedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("the given array was not sorted")
+    }
+}
+
+impl std::error::Error for NotSortedError {}
+
+/// A thin wrapper around `[T; N]` that upholds the invariant of being sorted in ascending order.
+///
+/// This is used for the byte indices of [`split_n_times`](crate::StrTools::split_n_times) and by
+/// [`find::non_escaped`](crate::find::non_escaped) to allow `O(log n)` lookup of delimiters via
+/// [`binary_search`](slice::binary_search) while still accepting plain arrays or single values at
+/// the call site.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sorted<T, const N: usize>([T; N]);
+
+impl<T: Ord, const N: usize> Sorted<T, N> {
+    /// Creates a new [`Sorted`] by sorting the given array.
+    pub fn new(mut array: [T; N]) -> Self {
+        array.sort_unstable();
+        Self(array)
+    }
+
+    /// Creates a new [`Sorted`] without checking whether `array` is actually sorted.
+    ///
+    /// # Safety
+    /// This is not `unsafe` as no memory safety relies on the invariant, but the lookup methods
+    /// will produce logic errors if `array` is not sorted in ascending order.
+    pub fn new_unchecked(array: [T; N]) -> Self {
+        debug_assert!(array.is_sorted(), "array was not sorted");
+        Self(array)
+    }
+
+    /// Returns `true` if `value` is contained in this array.
+    pub fn contains(&self, value: &T) -> bool {
+        self.0.binary_search(value).is_ok()
+    }
+}
+
+impl<T, const N: usize> Deref for Sorted<T, N> {
+    type Target = [T; N];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Ord> From<T> for Sorted<T, 1> {
+    fn from(value: T) -> Self {
+        Self([value])
+    }
+}
+
+impl<T: Ord, const N: usize> From<[T; N]> for Sorted<T, N> {
+    fn from(array: [T; N]) -> Self {
+        Self::new(array)
+    }
+}
+
+impl<T: Ord, const N: usize> TryFrom<&[T; N]> for Sorted<T, N>
+where
+    T: Copy,
+{
+    type Error = NotSortedError;
+
+    fn try_from(array: &[T; N]) -> Result<Self, Self::Error> {
+        if array.is_sorted() {
+            Ok(Self(*array))
+        } else {
+            Err(NotSortedError)
+        }
+    }
+}